@@ -1,6 +1,6 @@
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
-use std::hash::{Hash, Hasher};
+use std::hash::{BuildHasher, Hash, Hasher};
 
 /// Internal struct for storing key-value pairs
 /// along with their hash so that we don't
@@ -17,62 +17,362 @@ where
     hash: u64,
 }
 
-/// Public facing HashTable struct containing
-/// a vector of node buckets while keeping
-/// track of a target max load factor as
-/// well as the amount of entries
+/// Number of control bytes scanned together. Matches the width
+/// of an SSE2 `__m128i` register so a whole group can be compared
+/// against a tag in one instruction on x86_64.
+const GROUP_WIDTH: usize = 16;
+
+/// Control byte marking a slot that has never held an entry.
+const EMPTY: u8 = 0xFF;
+
+/// Control byte marking a slot whose entry was removed. Kept
+/// distinct from `EMPTY` so that probes started before the
+/// removal still know to keep scanning past it.
+const DELETED: u8 = 0x80;
+
+/// Header written by `HashTable::serialize`: capacity, size, and the
+/// load factor, each a fixed-width little-endian field so the layout
+/// doesn't depend on the host's endianness.
+const HEADER_SIZE: usize = 8 + 8 + 8;
+
+/// Splits a hash into `h1`, which selects the starting group, and
+/// `h2`, the 7-bit tag stored in the control byte. Shared by the live
+/// `HashTable` probing and the read-only `TableView` so both walk the
+/// same probe sequence for a given hash.
+fn h1_h2(hash: u64) -> (u64, u8) {
+    (hash >> 7, (hash & 0x7f) as u8)
+}
+
+/// Copies the (possibly wrapping) `GROUP_WIDTH` control bytes of
+/// `ctrl` starting at `group_start` into a local, contiguous array
+/// suitable for SIMD/SWAR comparison. Free-standing so it can be
+/// used against either a live table's `ctrl` array or the one
+/// currently being drained by `migrate_some`.
+fn load_group_from(ctrl: &[u8], group_start: usize) -> [u8; GROUP_WIDTH] {
+    let mask = ctrl.len() - 1;
+    let mut group = [0u8; GROUP_WIDTH];
+
+    for (i, slot) in group.iter_mut().enumerate() {
+        *slot = ctrl[(group_start + i) & mask];
+    }
+
+    group
+}
+
+/// Iterates the set bit positions of a group match mask, lowest
+/// first, the same way hashbrown's `BitMask` does.
+struct MatchedBits(u16);
+
+impl Iterator for MatchedBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            let bit = self.0.trailing_zeros() as usize;
+            self.0 &= self.0 - 1;
+            Some(bit)
+        }
+    }
+}
+
+/// The `BuildHasher` used when a `HashTable` isn't given one
+/// explicitly. Wraps the stdlib's `DefaultHasher` (SipHash) with a
+/// fixed seed, matching the hashing this crate has always used, which
+/// makes it a safe choice against adversarial keys at the cost of
+/// speed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultBuildHasher;
+
+impl BuildHasher for DefaultBuildHasher {
+    type Hasher = DefaultHasher;
+
+    fn build_hasher(&self) -> DefaultHasher {
+        DefaultHasher::new()
+    }
+}
+
+/// A non-cryptographic hasher ported from rustc's internal FxHash:
+/// each consumed word is folded into the running state with a
+/// rotate-xor-multiply step, which is dramatically cheaper than
+/// SipHash for small, fixed-size keys like integers. Unsuitable for
+/// adversarial input since the mixing isn't keyed.
+#[derive(Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+/// Magic constant FxHash multiplies by after each word; chosen in the
+/// original implementation for its bit-mixing properties (it's
+/// `u64::MAX / golden ratio`).
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(FX_SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, mut bytes: &[u8]) {
+        while bytes.len() >= 8 {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[..8]);
+            self.add_to_hash(u64::from_ne_bytes(word));
+            bytes = &bytes[8..];
+        }
+
+        if bytes.len() >= 4 {
+            let mut word = [0u8; 4];
+            word.copy_from_slice(&bytes[..4]);
+            self.add_to_hash(u32::from_ne_bytes(word) as u64);
+            bytes = &bytes[4..];
+        }
+
+        if bytes.len() >= 2 {
+            let mut word = [0u8; 2];
+            word.copy_from_slice(&bytes[..2]);
+            self.add_to_hash(u16::from_ne_bytes(word) as u64);
+            bytes = &bytes[2..];
+        }
+
+        if let Some(&byte) = bytes.first() {
+            self.add_to_hash(byte as u64);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Builds [`FxHasher`]s. Pass to [`HashTable::with_hasher`] to opt a
+/// table of small, non-adversarial keys (integers, chars, short
+/// strings) into faster hashing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxBuildHasher;
+
+impl BuildHasher for FxBuildHasher {
+    type Hasher = FxHasher;
+
+    fn build_hasher(&self) -> FxHasher {
+        FxHasher::default()
+    }
+}
+
+/// Number of old-table slots `migrate_some` drains per call. One
+/// `GROUP_WIDTH`'s worth, so a migration step costs about as much as
+/// a single probe, bounding the extra latency `put`/`get`/`remove`
+/// pay while a resize is in progress.
+const MIGRATION_BATCH: usize = GROUP_WIDTH;
+
+/// Public facing HashTable struct modeled on hashbrown/odht:
+/// a `ctrl` byte array shadows the `slots` array, where each
+/// control byte is `EMPTY`, `DELETED`, or the 7-bit `h2` tag of
+/// the hash stored in that slot. Probing scans `ctrl` one group
+/// of `GROUP_WIDTH` bytes at a time, so most lookups only ever
+/// touch the (much smaller, much more cache-friendly) control
+/// array instead of the key/value slots.
+///
+/// `S` is the `BuildHasher` used to hash keys, defaulting to
+/// [`DefaultBuildHasher`]. Swap in [`FxBuildHasher`] (or your own)
+/// via [`HashTable::with_hasher`] for workloads with small,
+/// non-adversarial keys.
+///
+/// Growing the table doesn't rehash everything inline. Once the load
+/// factor is crossed, `ctrl`/`slots` are swapped into `old_ctrl`/
+/// `old_slots` and a fresh, doubled pair takes their place; `put`,
+/// `get`, `remove`, and `entry` all consult both tables until
+/// `migration_cursor` catches up and the old one is dropped, so no
+/// single call pays for rehashing the whole table at once.
 #[derive(Debug)]
-pub struct HashTable<K, V>
+pub struct HashTable<K, V, S = DefaultBuildHasher>
 where
     K: Hash + PartialEq + Clone + Debug,
     V: Clone + Debug,
 {
-    buckets: Vec<Vec<HashNode<K, V>>>,
+    ctrl: Vec<u8>,
+    slots: Vec<Option<HashNode<K, V>>>,
+    old_ctrl: Option<Vec<u8>>,
+    old_slots: Option<Vec<Option<HashNode<K, V>>>>,
+    migration_cursor: usize,
     load_factor: f64,
+    hash_builder: S,
     pub size: usize,
 }
 
-impl<K, V> HashTable<K, V>
+impl<K, V> HashTable<K, V, DefaultBuildHasher>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+{
+    /// Initializes and returns a new HashTable
+    /// with an initial capacity of 16 slots
+    /// and a target max load factor of 1.0
+    pub fn new() -> Self {
+        Self::with_hasher(16, 1.0, DefaultBuildHasher)
+    }
+
+    /// Initializes and returns a new HashTable with at least the
+    /// requested capacity and target max load factor. The requested
+    /// capacity is rounded up to the next power-of-two multiple of
+    /// `GROUP_WIDTH` so the control array always divides evenly into
+    /// whole groups.
+    pub fn with(capacity: usize, load_factor: f64) -> Self {
+        Self::with_hasher(capacity, load_factor, DefaultBuildHasher)
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S>
 where
     K: Hash + PartialEq + Clone + Debug,
     V: Clone + Debug,
+    S: BuildHasher,
 {
-    /// Computes and returns the hash of
-    /// a key using the stdlib default
-    /// hasher
+    /// Initializes and returns a new HashTable with at least the
+    /// requested capacity and target max load factor, hashing keys
+    /// with `hash_builder` instead of the default hasher. Use this to
+    /// opt into [`FxBuildHasher`] for small, non-adversarial keys.
+    pub fn with_hasher(capacity: usize, load_factor: f64, hash_builder: S) -> Self {
+        let capacity = capacity.max(GROUP_WIDTH).next_power_of_two();
+
+        HashTable {
+            ctrl: vec![EMPTY; capacity],
+            slots: vec![None; capacity],
+            old_ctrl: None,
+            old_slots: None,
+            migration_cursor: 0,
+            load_factor,
+            hash_builder,
+            size: 0,
+        }
+    }
+
+    /// Computes and returns the hash of a key using this table's
+    /// `BuildHasher`.
     fn hash_of(&self, key: &K) -> u64 {
-        let mut s = DefaultHasher::new();
+        let mut s = self.hash_builder.build_hasher();
         key.hash(&mut s);
         s.finish()
     }
 
-    /// Hashes a key and mods it by the length of the buckets
-    /// vector to get the index of the bucket a key should be
-    /// placed in
-    fn index_of(&self, key: &K) -> usize {
-        (self.hash_of(key) % (self.buckets.len() as u64)) as usize
+    /// Number of `GROUP_WIDTH`-sized groups the control array is
+    /// divided into. Capacity is always a power of two multiple of
+    /// `GROUP_WIDTH`, so this is too, which lets group selection use
+    /// a mask instead of a modulo.
+    fn num_groups(&self) -> usize {
+        self.slots.len() / GROUP_WIDTH
     }
 
-    /// Initializes and returns a new HashTable
-    /// with an initial capacity of 16 buckets
-    /// and a target max load factor of 1.0
-    pub fn new() -> Self {
-        HashTable {
-            buckets: vec![vec![]; 16],
-            load_factor: 1.0,
-            size: 0,
+    /// Copies the (possibly wrapping) `GROUP_WIDTH` control bytes
+    /// starting at `group_start` into a local, contiguous array
+    /// suitable for SIMD/SWAR comparison.
+    fn load_group(&self, group_start: usize) -> [u8; GROUP_WIDTH] {
+        load_group_from(&self.ctrl, group_start)
+    }
+
+    /// Compares every byte of `group` against `tag`, returning a
+    /// bitmask with a `1` for each matching position. On x86_64 this
+    /// is a single `_mm_cmpeq_epi8` over the whole group; elsewhere
+    /// it falls back to the classic SWAR has-zero-byte trick over
+    /// two `u64` halves.
+    #[cfg(target_arch = "x86_64")]
+    fn match_byte(group: &[u8; GROUP_WIDTH], tag: u8) -> u16 {
+        use std::arch::x86_64::{_mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8};
+
+        unsafe {
+            let group_vec = _mm_loadu_si128(group.as_ptr() as *const _);
+            let tag_vec = _mm_set1_epi8(tag as i8);
+            let eq = _mm_cmpeq_epi8(group_vec, tag_vec);
+            _mm_movemask_epi8(eq) as u16
         }
     }
 
-    /// Initializes and returns a new HashTable
-    /// with the specified amount of buckets
-    /// and target max load factor
-    pub fn with(buckets: usize, load_factor: f64) -> Self {
-        HashTable {
-            buckets: vec![vec![]; buckets],
-            load_factor,
-            size: 0,
+    #[cfg(not(target_arch = "x86_64"))]
+    fn match_byte(group: &[u8; GROUP_WIDTH], tag: u8) -> u16 {
+        let broadcast = u64::from_ne_bytes([tag; 8]);
+        let mut mask = 0u16;
+
+        for (half, chunk) in group.chunks_exact(8).enumerate() {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            let word = u64::from_ne_bytes(bytes) ^ broadcast;
+
+            // Classic SWAR "does any byte equal zero" trick: a byte
+            // only keeps its high bit set in `has_zero` if that byte
+            // of `word` was all zero, i.e. matched `tag` before the xor.
+            let has_zero = word.wrapping_sub(0x0101_0101_0101_0101) & !word & 0x8080_8080_8080_8080;
+
+            for bit in 0..8 {
+                if (has_zero >> (bit * 8)) & 0x80 != 0 {
+                    mask |= 1 << (half * 8 + bit);
+                }
+            }
+        }
+
+        mask
+    }
+
+    fn match_tag(group: &[u8; GROUP_WIDTH], tag: u8) -> MatchedBits {
+        MatchedBits(Self::match_byte(group, tag))
+    }
+
+    fn match_empty(group: &[u8; GROUP_WIDTH]) -> MatchedBits {
+        MatchedBits(Self::match_byte(group, EMPTY))
+    }
+
+    /// Finds a slot to insert into within `group`, preferring an
+    /// `EMPTY` byte over a `DELETED` one (inserting into a tombstone
+    /// is just as valid, but `EMPTY` is cheaper to reason about since
+    /// it can't have been any other key). Shared by `_put` and `entry`,
+    /// which both scan for an existing key and, failing that, need the
+    /// first free slot along the same probe sequence.
+    fn first_insertion_point(group: &[u8; GROUP_WIDTH], group_start: usize, slot_mask: usize) -> Option<usize> {
+        if let Some(bit) = Self::match_empty(group).next() {
+            return Some((group_start + bit) & slot_mask);
+        }
+
+        if let Some(bit) = Self::match_tag(group, DELETED).next() {
+            return Some((group_start + bit) & slot_mask);
         }
+
+        None
+    }
+
+    /// Searches a `ctrl`/`slots` pair for `key`, returning its slot
+    /// index if present. Takes the arrays explicitly rather than
+    /// `&self` so it can be run against either the live table or the
+    /// one `migrate_some` is currently draining — the latter can be
+    /// packed completely full with no `EMPTY` byte to stop a probe,
+    /// since it won't gain any tombstones until migration starts, so
+    /// the scan is bounded by `num_groups` rather than relying on one.
+    fn find_in(ctrl: &[u8], slots: &[Option<HashNode<K, V>>], key: &K, hash: u64) -> Option<usize> {
+        let (h1, h2) = h1_h2(hash);
+        let num_groups = slots.len() / GROUP_WIDTH;
+        let group_mask = num_groups - 1;
+        let slot_mask = slots.len() - 1;
+        let mut group_idx = (h1 as usize) & group_mask;
+
+        for _ in 0..num_groups {
+            let group_start = group_idx * GROUP_WIDTH;
+            let group = load_group_from(ctrl, group_start);
+
+            for bit in Self::match_tag(&group, h2) {
+                let idx = (group_start + bit) & slot_mask;
+                if matches!(&slots[idx], Some(node) if node.key == *key) {
+                    return Some(idx);
+                }
+            }
+
+            if Self::match_empty(&group).next().is_some() {
+                return None;
+            }
+
+            group_idx = (group_idx + 1) & group_mask;
+        }
+
+        None
     }
 
     /// Internal put method that accepts a HashNode instead
@@ -80,30 +380,117 @@ where
     /// this separately is that when rehashing the table, we can
     /// simply pass all the old nodes into this method internally
     /// so that hashcodes don't need to be recomputed.
-    fn _put(&mut self, n: HashNode<K, V>) {
-        let index = (n.hash % self.buckets.len() as u64) as usize;
-        let bucket = &mut self.buckets[index];
-
-        for node in bucket.iter_mut() {
-            if n.key == node.key {
-                node.value = n.value;
-                return;
+    ///
+    /// Scans groups of control bytes starting from the group `h1`
+    /// selects: any byte matching `h2` is a candidate whose slot is
+    /// worth a full key comparison, and the first `EMPTY`/`DELETED`
+    /// byte seen along the way is remembered as the insertion point
+    /// in case the key isn't already present. The scan stops as soon
+    /// as a group contains an `EMPTY` byte, since that means the
+    /// probe sequence for this hash can't continue past it.
+    fn _put(&mut self, node: HashNode<K, V>) {
+        let (h1, h2) = h1_h2(node.hash);
+        let num_groups = self.num_groups();
+        let group_mask = num_groups - 1;
+        let slot_mask = self.slots.len() - 1;
+        let mut group_idx = (h1 as usize) & group_mask;
+        let mut insertion_point = None;
+
+        for _ in 0..num_groups {
+            let group_start = group_idx * GROUP_WIDTH;
+            let group = self.load_group(group_start);
+
+            for bit in Self::match_tag(&group, h2) {
+                let idx = (group_start + bit) & slot_mask;
+                if let Some(occupant) = &mut self.slots[idx] {
+                    if occupant.key == node.key {
+                        occupant.value = node.value;
+                        return;
+                    }
+                }
             }
-        }
 
-        bucket.push(HashNode {
-            key: n.key,
-            value: n.value,
-            hash: n.hash,
-        });
+            if insertion_point.is_none() {
+                insertion_point = Self::first_insertion_point(&group, group_start, slot_mask);
+            }
+
+            if Self::match_empty(&group).next().is_some() {
+                break;
+            }
+
+            group_idx = (group_idx + 1) & group_mask;
+        }
 
+        let idx = insertion_point.expect("load factor guarantees a free slot exists");
+        self.ctrl[idx] = h2;
+        self.slots[idx] = Some(node);
         self.size += 1;
     }
 
-    /// Inserts a key-value pair into the HashTable and
-    /// returns the updated HashTable. If the target
-    /// maximum load factor is surpassed, rehashing
-    /// automatically occurs.
+    /// Moves a node that's already counted in `size` into the live
+    /// table — used to relocate entries out of `old_slots`, where
+    /// `_put` would otherwise double-count them as new insertions.
+    fn _relocate(&mut self, node: HashNode<K, V>) {
+        self._put(node);
+        self.size -= 1;
+    }
+
+    /// Starts an incremental resize: allocates a fresh, doubled
+    /// `ctrl`/`slots` pair and moves the current arrays aside into
+    /// `old_ctrl`/`old_slots` rather than reinserting every node right
+    /// away. Entries are relocated a few at a time by `migrate_some`
+    /// instead, so crossing the load factor doesn't make any single
+    /// `put` pay for rehashing the whole table. A no-op if a resize is
+    /// already in progress.
+    fn begin_resize(&mut self) {
+        if self.old_slots.is_some() {
+            return;
+        }
+
+        let new_capacity = self.slots.len() * 2;
+        self.old_ctrl = Some(std::mem::replace(&mut self.ctrl, vec![EMPTY; new_capacity]));
+        self.old_slots = Some(std::mem::replace(&mut self.slots, vec![None; new_capacity]));
+        self.migration_cursor = 0;
+    }
+
+    /// Moves up to `MIGRATION_BATCH` entries from `old_slots` into the
+    /// live table, advancing `migration_cursor` past whichever old
+    /// slots it visits (occupied or not). Once the cursor reaches the
+    /// end of the old array, the old table is dropped. A no-op if no
+    /// resize is in progress. Called from `put`, `remove`, and `entry`
+    /// so migration keeps making progress across whichever operation
+    /// the caller happens to perform next.
+    fn migrate_some(&mut self) {
+        if self.old_slots.is_none() {
+            return;
+        }
+
+        let mut old_slots = self.old_slots.take().unwrap();
+        let old_capacity = old_slots.len();
+        let end = (self.migration_cursor + MIGRATION_BATCH).min(old_capacity);
+
+        for slot in &mut old_slots[self.migration_cursor..end] {
+            if let Some(node) = slot.take() {
+                self._relocate(node);
+            }
+        }
+
+        self.migration_cursor = end;
+
+        if self.migration_cursor >= old_capacity {
+            self.old_ctrl = None;
+            self.migration_cursor = 0;
+        } else {
+            self.old_slots = Some(old_slots);
+        }
+    }
+
+    /// Inserts a key-value pair into the HashTable and returns the
+    /// updated HashTable. If the target maximum load factor is
+    /// surpassed, an incremental resize is started (see
+    /// [`HashTable`]'s docs); either way, this call also migrates a
+    /// batch of entries if a resize from an earlier `put` is still in
+    /// progress.
     ///
     /// # Examples
     ///
@@ -118,32 +505,41 @@ where
     pub fn put(mut self, key: K, value: V) -> Self {
         let hash = self.hash_of(&key);
 
-        let hash_node = HashNode { key, value, hash };
+        let found = if let Some(old_slots) = &self.old_slots {
+            let old_ctrl = self.old_ctrl.as_ref().unwrap();
+            Self::find_in(old_ctrl, old_slots, &key, hash)
+        } else {
+            None
+        };
 
-        self._put(hash_node);
+        if let Some(idx) = found {
+            self.old_slots.as_mut().unwrap()[idx].as_mut().unwrap().value = value;
+            self.migrate_some();
+            return self;
+        }
 
-        // Rehashing if the max load factor is surpassed
-        if (self.size as f64 / self.buckets.len() as f64) >= self.load_factor {
-            // Create a new bucket vector that's twice as large as the previous one
-            let new_buckets = vec![vec![]; self.buckets.len() * 2];
-            // Take ownership of all the old nodes. This is why this method
-            // requires ownership of self.
-            let old = self.buckets.into_iter().flat_map(Vec::into_iter);
+        let hash_node = HashNode { key, value, hash };
 
-            self.buckets = new_buckets;
-            self.size = 0;
+        self._put(hash_node);
 
-            for node in old {
-                self._put(node);
-            }
+        if (self.size as f64 / self.slots.len() as f64) >= self.load_factor {
+            self.begin_resize();
         }
 
+        self.migrate_some();
+
         self
     }
 
     /// Retrieves an optional read-only reference to a value from
     /// the HashTable corresponding to the given key. If the key
-    /// doesn't exist in the table, None is returned.
+    /// doesn't exist in the table, None is returned. Consults the
+    /// table being drained by an in-progress resize too, so a lookup
+    /// never misses an entry that hasn't migrated yet. Unlike
+    /// `get_mut`/`put`/`remove`/`entry`, this doesn't advance the
+    /// migration itself, since doing so needs `&mut self`; a workload
+    /// that only ever calls `get` after crossing the load factor will
+    /// keep consulting both tables until a mutating call comes along.
     ///
     /// # Examples
     ///
@@ -157,29 +553,502 @@ where
     /// assert_eq!(table.get('b'), None);
     /// ```
     pub fn get(&self, key: K) -> Option<&V> {
-        let index = self.index_of(&key);
-        let bucket = &self.buckets[index];
+        let hash = self.hash_of(&key);
 
-        for n in bucket.iter() {
-            if n.key == key {
-                return Some(&n.value);
-            }
+        if let Some(idx) = Self::find_in(&self.ctrl, &self.slots, &key, hash) {
+            return self.slots[idx].as_ref().map(|node| &node.value);
+        }
+
+        let old_ctrl = self.old_ctrl.as_ref()?;
+        let old_slots = self.old_slots.as_ref()?;
+        let idx = Self::find_in(old_ctrl, old_slots, &key, hash)?;
+
+        old_slots[idx].as_ref().map(|node| &node.value)
+    }
+
+    /// Removes a key from the HashTable, returning its value if it
+    /// was present. The vacated slot's control byte is set to
+    /// `DELETED` rather than `EMPTY` so that probes for other keys
+    /// which hashed into the same group before the removal keep
+    /// scanning past it instead of stopping early. Also checks the
+    /// table being drained by an in-progress resize, and nudges the
+    /// migration forward a batch either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rds::hashtable::*;
+    ///
+    /// let mut table = HashTable::new();
+    /// table = table.put('a', 1);
+    /// assert_eq!(table.remove(&'a'), Some(1));
+    /// assert_eq!(table.get('a'), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let hash = self.hash_of(key);
+
+        if let Some(idx) = Self::find_in(&self.ctrl, &self.slots, key, hash) {
+            self.ctrl[idx] = DELETED;
+            self.size -= 1;
+            let value = self.slots[idx].take().map(|node| node.value);
+            self.migrate_some();
+            return value;
+        }
+
+        let found = if let Some(old_slots) = &self.old_slots {
+            let old_ctrl = self.old_ctrl.as_ref().unwrap();
+            Self::find_in(old_ctrl, old_slots, key, hash)
+        } else {
+            None
+        };
+
+        if let Some(idx) = found {
+            self.old_ctrl.as_mut().unwrap()[idx] = DELETED;
+            self.size -= 1;
+            let value = self.old_slots.as_mut().unwrap()[idx].take().map(|node| node.value);
+            self.migrate_some();
+            return value;
+        }
+
+        self.migrate_some();
+
+        None
+    }
+
+    /// Retrieves an optional mutable reference to a value from the
+    /// HashTable corresponding to the given key. If the key doesn't
+    /// exist in the table, None is returned. Consults the table being
+    /// drained by an in-progress resize too, and nudges the migration
+    /// forward a batch beforehand, same as `put`/`remove`/`entry` — a
+    /// workload that only calls `get_mut` (or `get`, which can't)
+    /// after crossing the load factor would otherwise never finish
+    /// draining the old table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rds::hashtable::*;
+    ///
+    /// let mut table = HashTable::new();
+    /// table = table.put('a', 1);
+    /// *table.get_mut(&'a').unwrap() += 1;
+    /// assert_eq!(table.get('a'), Some(&2));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let hash = self.hash_of(key);
+
+        self.migrate_some();
+
+        if let Some(idx) = Self::find_in(&self.ctrl, &self.slots, key, hash) {
+            return self.slots[idx].as_mut().map(|node| &mut node.value);
+        }
+
+        let found = if let Some(old_slots) = &self.old_slots {
+            let old_ctrl = self.old_ctrl.as_ref().unwrap();
+            Self::find_in(old_ctrl, old_slots, key, hash)
+        } else {
+            None
+        };
+
+        if let Some(idx) = found {
+            return self.old_slots.as_mut().unwrap()[idx].as_mut().map(|node| &mut node.value);
         }
 
         None
     }
 
+    /// Returns a handle onto this key's slot, letting a caller inspect
+    /// or insert a value without a second probe/search. The group scan
+    /// that locates (or reserves) the slot runs once here; `Entry`'s
+    /// methods just act on the result. If a resize is in progress and
+    /// the key is still sitting in the table being drained, it's
+    /// hoisted into the live table first so the entry has one stable
+    /// slot to hand a reference into; either way this also migrates a
+    /// batch of whatever's left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rds::hashtable::*;
+    ///
+    /// let mut table = HashTable::new();
+    /// *table.entry('a').or_insert(0) += 1;
+    /// *table.entry('a').or_insert(0) += 1;
+    /// assert_eq!(table.get('a'), Some(&2));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash_of(&key);
+
+        let found = if let Some(old_slots) = &self.old_slots {
+            let old_ctrl = self.old_ctrl.as_ref().unwrap();
+            Self::find_in(old_ctrl, old_slots, &key, hash)
+        } else {
+            None
+        };
+
+        if let Some(idx) = found {
+            self.old_ctrl.as_mut().unwrap()[idx] = DELETED;
+            let node = self.old_slots.as_mut().unwrap()[idx].take().unwrap();
+            self._relocate(node);
+        }
+
+        self.migrate_some();
+
+        let (h1, h2) = h1_h2(hash);
+        let num_groups = self.num_groups();
+        let group_mask = num_groups - 1;
+        let slot_mask = self.slots.len() - 1;
+        let mut group_idx = (h1 as usize) & group_mask;
+        let mut insertion_point = None;
+
+        for _ in 0..num_groups {
+            let group_start = group_idx * GROUP_WIDTH;
+            let group = self.load_group(group_start);
+
+            for bit in Self::match_tag(&group, h2) {
+                let idx = (group_start + bit) & slot_mask;
+                if matches!(&self.slots[idx], Some(node) if node.key == key) {
+                    return Entry::Occupied(OccupiedEntry { table: self, index: idx });
+                }
+            }
+
+            if insertion_point.is_none() {
+                insertion_point = Self::first_insertion_point(&group, group_start, slot_mask);
+            }
+
+            if Self::match_empty(&group).next().is_some() {
+                break;
+            }
+
+            group_idx = (group_idx + 1) & group_mask;
+        }
+
+        let insertion_index = insertion_point.expect("load factor guarantees a free slot exists");
+
+        Entry::Vacant(VacantEntry {
+            table: self,
+            key,
+            hash,
+            h2,
+            insertion_index,
+        })
+    }
+
     /// Produces and returns a String representing the HashTable,
-    /// displaying all key-value pairs.
+    /// displaying all key-value pairs, including any still sitting in
+    /// a table being drained by an in-progress resize.
     pub fn to_string(&self) -> String {
         let mut s = String::new();
 
-        for n in self.buckets.iter().flatten() {
+        for n in self.slots.iter().flatten() {
             s = format!("{}\n{:?} : {:?}", s, n.key, n.value);
         }
 
+        if let Some(old_slots) = &self.old_slots {
+            for n in old_slots.iter().flatten() {
+                s = format!("{}\n{:?} : {:?}", s, n.key, n.value);
+            }
+        }
+
         s
     }
+
+    /// Serializes this HashTable into a single contiguous buffer: a
+    /// small header followed by the raw control array and then the
+    /// raw key/value slot array, each key and value encoded to a
+    /// fixed-size little-endian byte array via `C`. The result has
+    /// no pointers and no host-dependent layout, so it can be written
+    /// to a file on one machine and later read back (or `mmap`ed and
+    /// queried in place via [`TableView`]) on another. If a resize is
+    /// in progress, the old table is fully drained first so the
+    /// serialized layout only ever has to represent one table.
+    pub fn serialize<C, const KN: usize, const VN: usize>(&mut self) -> Vec<u8>
+    where
+        C: Config<K, V, KN, VN>,
+    {
+        while self.old_slots.is_some() {
+            self.migrate_some();
+        }
+
+        let capacity = self.slots.len();
+        let mut buf = Vec::with_capacity(HEADER_SIZE + capacity * (1 + KN + VN));
+
+        buf.extend_from_slice(&(capacity as u64).to_le_bytes());
+        buf.extend_from_slice(&(self.size as u64).to_le_bytes());
+        buf.extend_from_slice(&self.load_factor.to_le_bytes());
+        buf.extend_from_slice(&self.ctrl);
+
+        for slot in &self.slots {
+            match slot {
+                Some(node) => {
+                    buf.extend_from_slice(&C::encode_key(&node.key));
+                    buf.extend_from_slice(&C::encode_value(&node.value));
+                }
+                None => buf.resize(buf.len() + KN + VN, 0),
+            }
+        }
+
+        buf
+    }
+}
+
+/// A handle onto a key's slot in a `HashTable`, obtained from
+/// [`HashTable::entry`]. Lets a caller conditionally insert without
+/// probing the table twice.
+pub enum Entry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+    S: BuildHasher,
+{
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+    S: BuildHasher,
+{
+    /// Ensures a value is present, inserting `default` if the entry
+    /// is vacant, then returns a mutable reference to it.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Like [`Entry::or_insert`], but only computes the default value
+    /// if the entry is vacant.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Applies `f` to the value if the entry is occupied, then
+    /// returns the entry unchanged so it can still be followed by
+    /// `or_insert`/`or_insert_with`.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// An occupied [`Entry`]: the key was already present when
+/// [`HashTable::entry`] was called.
+pub struct OccupiedEntry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+{
+    table: &'a mut HashTable<K, V, S>,
+    index: usize,
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+{
+    fn get_mut(&mut self) -> &mut V {
+        &mut self.table.slots[self.index].as_mut().unwrap().value
+    }
+
+    fn into_mut(self) -> &'a mut V {
+        &mut self.table.slots[self.index].as_mut().unwrap().value
+    }
+}
+
+/// A vacant [`Entry`]: the key wasn't present when
+/// [`HashTable::entry`] was called, but a slot for it (its ideal slot
+/// or the first tombstone/empty slot found along its probe sequence)
+/// was already located.
+pub struct VacantEntry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+{
+    table: &'a mut HashTable<K, V, S>,
+    key: K,
+    hash: u64,
+    h2: u8,
+    insertion_index: usize,
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where
+    K: Hash + PartialEq + Clone + Debug,
+    V: Clone + Debug,
+    S: BuildHasher,
+{
+    /// Inserts `value` into the slot this entry already located,
+    /// without re-hashing or re-probing for the key. If doing so
+    /// surpasses the target load factor, an incremental resize is
+    /// started afterwards, same as `put`.
+    fn insert(self, value: V) -> &'a mut V {
+        let VacantEntry {
+            table,
+            key,
+            hash,
+            h2,
+            insertion_index,
+        } = self;
+
+        table.ctrl[insertion_index] = h2;
+        table.slots[insertion_index] = Some(HashNode {
+            key: key.clone(),
+            value,
+            hash,
+        });
+        table.size += 1;
+
+        if (table.size as f64 / table.slots.len() as f64) >= table.load_factor {
+            table.begin_resize();
+            table.migrate_some();
+            return table.get_mut(&key).unwrap();
+        }
+
+        table.migrate_some();
+
+        &mut table.slots[insertion_index].as_mut().unwrap().value
+    }
+}
+
+/// Implemented by callers who want a `HashTable<K, V>` to be
+/// serializable: provides a fixed-size byte encoding for keys and
+/// values (independent of host endianness or pointer width) plus the
+/// hash function `TableView` should use to re-derive a key's slot.
+pub trait Config<K, V, const KN: usize, const VN: usize> {
+    /// Encodes a key into a fixed-size little-endian byte array.
+    fn encode_key(key: &K) -> [u8; KN];
+    /// Decodes a key previously produced by `encode_key`.
+    fn decode_key(bytes: &[u8; KN]) -> K;
+    /// Encodes a value into a fixed-size little-endian byte array.
+    fn encode_value(value: &V) -> [u8; VN];
+    /// Decodes a value previously produced by `encode_value`.
+    fn decode_value(bytes: &[u8; VN]) -> V;
+    /// Hashes a key the same way a live `HashTable` would, so a
+    /// `TableView` can walk the same probe sequence `put` used.
+    fn hash(key: &K) -> u64;
+}
+
+/// Marks `TableView` as logically holding a `K`, `V`, and `C` without
+/// actually storing any, so the compiler still enforces that a view
+/// can't be used with a mismatched `Config`. A type alias rather than
+/// the bare `fn() -> (K, V, C)` pointer type sidesteps clippy's
+/// `type_complexity` lint on the struct field.
+type ConfigMarker<K, V, C> = std::marker::PhantomData<fn() -> (K, V, C)>;
+
+/// A read-only view over a byte buffer produced by
+/// [`HashTable::serialize`]. Built with zero up-front cost: no keys
+/// or values are decoded until `get` finds a matching control byte,
+/// which makes this suitable for querying directly over an `mmap`ed
+/// file.
+pub struct TableView<'a, K, V, C, const KN: usize, const VN: usize>
+where
+    C: Config<K, V, KN, VN>,
+{
+    bytes: &'a [u8],
+    capacity: usize,
+    pub size: usize,
+    _config: ConfigMarker<K, V, C>,
+}
+
+impl<'a, K, V, C, const KN: usize, const VN: usize> TableView<'a, K, V, C, KN, VN>
+where
+    K: PartialEq,
+    C: Config<K, V, KN, VN>,
+{
+    const SLOT_STRIDE: usize = KN + VN;
+
+    /// Validates the header of `bytes` and builds a view over it
+    /// without copying or decoding the slot array. Returns `None` if
+    /// the buffer is too short or its declared capacity doesn't match
+    /// its actual length.
+    pub fn from_bytes(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len() < HEADER_SIZE {
+            return None;
+        }
+
+        let capacity = u64::from_le_bytes(bytes[0..8].try_into().ok()?) as usize;
+        let size = u64::from_le_bytes(bytes[8..16].try_into().ok()?) as usize;
+        let expected_len = HEADER_SIZE + capacity + capacity * Self::SLOT_STRIDE;
+
+        if capacity == 0 || bytes.len() != expected_len {
+            return None;
+        }
+
+        Some(TableView {
+            bytes,
+            capacity,
+            size,
+            _config: std::marker::PhantomData,
+        })
+    }
+
+    fn ctrl_byte(&self, index: usize) -> u8 {
+        self.bytes[HEADER_SIZE + index]
+    }
+
+    fn slot_bytes(&self, index: usize) -> &'a [u8] {
+        let header = HEADER_SIZE + self.capacity;
+        let start = header + index * Self::SLOT_STRIDE;
+        &self.bytes[start..start + Self::SLOT_STRIDE]
+    }
+
+    /// Looks up `key`, decoding only the slot whose control byte
+    /// matches `key`'s `h2` tag and whose decoded key compares equal,
+    /// mirroring the group-by-group probe a live `HashTable` uses.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let hash = C::hash(key);
+        let (h1, h2) = h1_h2(hash);
+        let num_groups = self.capacity / GROUP_WIDTH;
+        let group_mask = num_groups - 1;
+        let slot_mask = self.capacity - 1;
+        let mut group_idx = (h1 as usize) & group_mask;
+
+        for _ in 0..num_groups {
+            let group_start = group_idx * GROUP_WIDTH;
+
+            for offset in 0..GROUP_WIDTH {
+                let idx = (group_start + offset) & slot_mask;
+                let ctrl = self.ctrl_byte(idx);
+
+                if ctrl == h2 {
+                    let slot = self.slot_bytes(idx);
+                    let key_bytes: [u8; KN] = slot[0..KN].try_into().unwrap();
+
+                    if C::decode_key(&key_bytes) == *key {
+                        let value_bytes: [u8; VN] = slot[KN..KN + VN].try_into().unwrap();
+                        return Some(C::decode_value(&value_bytes));
+                    }
+                }
+            }
+
+            if (0..GROUP_WIDTH).any(|offset| self.ctrl_byte((group_start + offset) & slot_mask) == EMPTY) {
+                return None;
+            }
+
+            group_idx = (group_idx + 1) & group_mask;
+        }
+
+        None
+    }
 }
 
 #[cfg(test)]
@@ -205,4 +1074,263 @@ mod tests {
 
         assert_eq!(m.size, 25);
     }
+
+    #[test]
+    fn test_remove() {
+        let mut m: HashTable<char, i32> = HashTable::new();
+        m = m.put('a', 1);
+        m = m.put('b', 2);
+        m = m.put('c', 3);
+
+        assert_eq!(m.remove(&'b'), Some(2));
+        assert_eq!(m.get('b'), None);
+        assert_eq!(m.get('a'), Some(&1));
+        assert_eq!(m.get('c'), Some(&3));
+        assert_eq!(m.size, 2);
+        assert_eq!(m.remove(&'z'), None);
+    }
+
+    #[test]
+    fn test_remove_then_reinsert_across_group_boundary() {
+        let mut m: HashTable<i32, i32> = HashTable::new();
+
+        for i in 0..16 {
+            m = m.put(i, i * 10);
+        }
+
+        for i in 0..8 {
+            assert_eq!(m.remove(&i), Some(i * 10));
+        }
+
+        for i in 8..16 {
+            assert_eq!(m.get(i), Some(&(i * 10)));
+        }
+    }
+
+    struct I32Config;
+
+    impl Config<i32, i32, 4, 4> for I32Config {
+        fn encode_key(key: &i32) -> [u8; 4] {
+            key.to_le_bytes()
+        }
+
+        fn decode_key(bytes: &[u8; 4]) -> i32 {
+            i32::from_le_bytes(*bytes)
+        }
+
+        fn encode_value(value: &i32) -> [u8; 4] {
+            value.to_le_bytes()
+        }
+
+        fn decode_value(bytes: &[u8; 4]) -> i32 {
+            i32::from_le_bytes(*bytes)
+        }
+
+        fn hash(key: &i32) -> u64 {
+            let mut s = DefaultHasher::new();
+            key.hash(&mut s);
+            s.finish()
+        }
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let mut m: HashTable<i32, i32> = HashTable::new();
+
+        for i in 0..20 {
+            m = m.put(i, i * i);
+        }
+
+        let bytes = m.serialize::<I32Config, 4, 4>();
+        let view: TableView<i32, i32, I32Config, 4, 4> = TableView::from_bytes(&bytes).unwrap();
+
+        assert_eq!(view.size, m.size);
+
+        for i in 0..20 {
+            assert_eq!(view.get(&i), Some(i * i));
+        }
+
+        assert_eq!(view.get(&100), None);
+    }
+
+    #[test]
+    fn test_view_get_miss_on_fully_occupied_table_terminates() {
+        // A table with no EMPTY control byte anywhere (every slot
+        // occupied, capacity == load_factor * capacity) used to send
+        // TableView::get into an infinite loop on a miss, since its
+        // probe only stopped on an EMPTY byte. Load factor > 1.0 here
+        // guarantees `put` never triggers a resize, so the table stays
+        // completely full and no byte is ever left EMPTY.
+        let mut m: HashTable<i32, i32> = HashTable::with(16, 2.0);
+
+        for i in 0..16 {
+            m = m.put(i, i);
+        }
+
+        let bytes = m.serialize::<I32Config, 4, 4>();
+        let view: TableView<i32, i32, I32Config, 4, 4> = TableView::from_bytes(&bytes).unwrap();
+
+        assert_eq!(view.get(&9999), None);
+    }
+
+    #[test]
+    fn test_fx_hasher() {
+        let mut m: HashTable<i32, i32, FxBuildHasher> =
+            HashTable::with_hasher(16, 1.0, FxBuildHasher);
+
+        for i in 0..50 {
+            m = m.put(i, i * 2);
+        }
+
+        for i in 0..50 {
+            assert_eq!(m.get(i), Some(&(i * 2)));
+        }
+
+        assert_eq!(m.size, 50);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut m: HashTable<char, i32> = HashTable::new();
+        m = m.put('a', 1);
+
+        *m.get_mut(&'a').unwrap() += 41;
+        assert_eq!(m.get('a'), Some(&42));
+        assert_eq!(m.get_mut(&'z'), None);
+    }
+
+    #[test]
+    fn test_entry_or_insert() {
+        let mut m: HashTable<char, i32> = HashTable::new();
+
+        *m.entry('a').or_insert(0) += 1;
+        *m.entry('a').or_insert(0) += 1;
+        *m.entry('b').or_insert_with(|| 10) += 1;
+
+        assert_eq!(m.get('a'), Some(&2));
+        assert_eq!(m.get('b'), Some(&11));
+        assert_eq!(m.size, 2);
+    }
+
+    #[test]
+    fn test_entry_and_modify() {
+        let mut m: HashTable<char, i32> = HashTable::new();
+        m = m.put('a', 1);
+
+        m.entry('a').and_modify(|v| *v += 100).or_insert(0);
+        m.entry('b').and_modify(|v| *v += 100).or_insert(5);
+
+        assert_eq!(m.get('a'), Some(&101));
+        assert_eq!(m.get('b'), Some(&5));
+    }
+
+    #[test]
+    fn test_incremental_resize_in_progress() {
+        let mut m: HashTable<i32, i32> = HashTable::with(64, 1.0);
+
+        for i in 0..64 {
+            m = m.put(i, i * i);
+        }
+
+        // Crossing the load factor starts a migration instead of
+        // rehashing everything inline, so part of the table should
+        // still be sitting in the array being drained.
+        assert!(m.old_slots.is_some());
+
+        for i in 0..64 {
+            assert_eq!(m.get(i), Some(&(i * i)));
+        }
+
+        for i in 64..80 {
+            m = m.put(i, i * i);
+        }
+
+        assert!(m.old_slots.is_none());
+        assert_eq!(m.size, 80);
+
+        for i in 0..80 {
+            assert_eq!(m.get(i), Some(&(i * i)));
+        }
+    }
+
+    #[test]
+    fn test_get_mut_and_remove_during_migration() {
+        let mut m: HashTable<i32, i32> = HashTable::with(64, 1.0);
+
+        for i in 0..64 {
+            m = m.put(i, i);
+        }
+
+        assert!(m.old_slots.is_some());
+
+        *m.get_mut(&5).unwrap() += 100;
+        assert_eq!(m.get(5), Some(&105));
+
+        assert_eq!(m.remove(&10), Some(10));
+        assert_eq!(m.get(10), None);
+        assert_eq!(m.size, 63);
+    }
+
+    #[test]
+    fn test_get_mut_only_workload_finishes_migration() {
+        let mut m: HashTable<i32, i32> = HashTable::with(64, 1.0);
+
+        for i in 0..64 {
+            m = m.put(i, i);
+        }
+
+        assert!(m.old_slots.is_some());
+
+        // A workload that never calls `put`/`remove`/`entry` again
+        // should still finish draining the old table eventually,
+        // since `get_mut` advances the migration too.
+        for _ in 0..64 {
+            m.get_mut(&0);
+        }
+
+        assert!(m.old_slots.is_none());
+
+        for i in 0..64 {
+            assert_eq!(m.get(i), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_entry_during_migration() {
+        let mut m: HashTable<i32, i32> = HashTable::with(64, 1.0);
+
+        for i in 0..64 {
+            m = m.put(i, i);
+        }
+
+        assert!(m.old_slots.is_some());
+
+        *m.entry(20).or_insert(0) += 1;
+        assert_eq!(m.get(20), Some(&21));
+        assert_eq!(m.size, 64);
+
+        *m.entry(1000).or_insert(0) += 1;
+        assert_eq!(m.get(1000), Some(&1));
+        assert_eq!(m.size, 65);
+    }
+
+    #[test]
+    fn test_serialize_during_migration_drains_old_table() {
+        let mut m: HashTable<i32, i32> = HashTable::with(64, 1.0);
+
+        for i in 0..64 {
+            m = m.put(i, i * i);
+        }
+
+        assert!(m.old_slots.is_some());
+
+        let bytes = m.serialize::<I32Config, 4, 4>();
+        assert!(m.old_slots.is_none());
+
+        let view: TableView<i32, i32, I32Config, 4, 4> = TableView::from_bytes(&bytes).unwrap();
+
+        for i in 0..64 {
+            assert_eq!(view.get(&i), Some(i * i));
+        }
+    }
 }
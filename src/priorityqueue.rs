@@ -1,27 +1,39 @@
-/// Simple Max Priority Queue
-/// struct which only holds onto
-/// a 32-bit integer heap.
-pub struct MaxPQ {
-    heap: Vec<i32>,
+use std::cmp::Ordering;
+
+/// A binary heap over any `Ord` type, always kept in max-first order
+/// (the greatest element is at the root). 0-indexed, so no sentinel
+/// value is needed: a node's children sit at `2i+1`/`2i+2` and its
+/// parent at `(i-1)/2`.
+///
+/// For a min-queue, wrap elements in [`std::cmp::Reverse`] — flipping
+/// the wrapped type's `Ord` impl turns "largest first" into "smallest
+/// first" without a second heap implementation.
+///
+/// # Examples
+///
+/// ```
+/// use rds::priorityqueue::*;
+/// use std::cmp::Reverse;
+///
+/// let mut min_pq = PriorityQueue::from(vec![Reverse(3), Reverse(1), Reverse(2)]);
+/// assert_eq!(min_pq.del_max(), Some(Reverse(1)));
+/// ```
+pub struct PriorityQueue<T: Ord> {
+    heap: Vec<T>,
 }
 
-impl MaxPQ {
-    /// Returns an empty MaxPQ. Note that
-    /// this implies the first element
-    /// of the heap is initialized to 0.
-    pub fn empty() -> MaxPQ {
-        MaxPQ { heap: vec![0] }
+impl<T: Ord> PriorityQueue<T> {
+    /// Returns an empty PriorityQueue.
+    pub fn empty() -> PriorityQueue<T> {
+        PriorityQueue { heap: Vec::new() }
     }
 
-    /// Accepts a vector of integers to be
-    /// used to form a heap and returns a
-    /// new MaxPQ. 0 is prepended to the
-    /// input vector before it's heapified.
-    pub fn from(mut values: Vec<i32>) -> MaxPQ {
-        values.insert(0, 0);
-        let mut mpq = MaxPQ { heap: values };
-        mpq.heapify();
-        mpq
+    /// Accepts a vector of values to be used to form a heap and
+    /// returns a new PriorityQueue.
+    pub fn from(values: Vec<T>) -> PriorityQueue<T> {
+        let mut pq = PriorityQueue { heap: values };
+        pq.heapify();
+        pq
     }
 
     /// Internal swim method for moving an element
@@ -29,10 +41,12 @@ impl MaxPQ {
     fn swim(&mut self, i: usize) {
         let mut ptr = i;
 
-        while ptr > 1 {
-            if self.heap[ptr] > self.heap[ptr / 2] {
-                self.heap.swap(ptr, ptr / 2);
-                ptr = ptr / 2;
+        while ptr > 0 {
+            let parent = (ptr - 1) / 2;
+
+            if self.heap[ptr].cmp(&self.heap[parent]) == Ordering::Greater {
+                self.heap.swap(ptr, parent);
+                ptr = parent;
             } else {
                 break;
             }
@@ -44,14 +58,16 @@ impl MaxPQ {
     fn sink(&mut self, i: usize) {
         let mut ptr = i;
 
-        while ptr * 2 < self.heap.len() {
+        while 2 * ptr + 1 < self.heap.len() {
+            let left = 2 * ptr + 1;
+            let right = 2 * ptr + 2;
+
             // Pick the greater of the two children to swap with
-            let greater =
-                if 2 * ptr + 1 == self.heap.len() || self.heap[2 * ptr + 1] < self.heap[2 * ptr] {
-                    2 * ptr
-                } else {
-                    2 * ptr + 1
-                };
+            let greater = if right == self.heap.len() || self.heap[right] < self.heap[left] {
+                left
+            } else {
+                right
+            };
 
             if self.heap[ptr] < self.heap[greater] {
                 self.heap.swap(ptr, greater);
@@ -62,74 +78,92 @@ impl MaxPQ {
         }
     }
 
-    /// Insert a value into the MaxPQ.
+    /// Insert a value into the PriorityQueue.
     ///
     /// # Examples
     ///
     /// ```
     /// use rds::priorityqueue::*;
     ///
-    /// let mut mpq = MaxPQ::from(vec![1, 2, 3, 4, 5]);
-    /// mpq.insert(6);
+    /// let mut pq = PriorityQueue::from(vec![1, 2, 3, 4, 5]);
+    /// pq.insert(6);
     ///
-    /// assert_eq!(6, mpq.del_max());
+    /// assert_eq!(Some(6), pq.del_max());
     /// ```
-    pub fn insert(&mut self, i: i32) {
-        self.heap.push(i);
+    pub fn insert(&mut self, value: T) {
+        self.heap.push(value);
         self.swim(self.heap.len() - 1)
     }
 
-    /// Remove the current maximum value
-    /// from the MaxPQ. The value is
-    /// returned after it is removed from
-    /// the PriorityQueue and after
-    /// the remaining values are re-adjusted.
+    /// Remove the current maximum value from the PriorityQueue,
+    /// returning it after the remaining values are re-adjusted. None
+    /// if the PriorityQueue is empty.
+    ///
+    /// This is a deliberate break from `MaxPQ::del_max`'s old `-> i32`
+    /// signature: that version used a sentinel 0 in an unused heap
+    /// slot 0 to signal "empty" (indistinguishable from an actually
+    /// stored 0), which doesn't generalize to an arbitrary `Ord` type
+    /// anyway. Callers need to handle `None` where they previously
+    /// got a bogus sentinel value back.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rds::priorityqueue::*;
     ///
-    /// let mut mpq = MaxPQ::from(vec![1, 2, 3, 4, 5]);
+    /// let mut pq = PriorityQueue::from(vec![1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(5, mpq.del_max());
-    /// assert_eq!(4, mpq.del_max());
+    /// assert_eq!(Some(5), pq.del_max());
+    /// assert_eq!(Some(4), pq.del_max());
     /// ```
-    pub fn del_max(&mut self) -> i32 {
-        let max = self.heap[1];
+    pub fn del_max(&mut self) -> Option<T> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
         let last = self.heap.len() - 1;
-        self.heap.swap(1, last);
-        self.heap.remove(self.heap.len() - 1);
-        self.sink(1);
+        self.heap.swap(0, last);
+        let max = self.heap.pop();
+
+        if !self.heap.is_empty() {
+            self.sink(0);
+        }
+
         max
     }
 
     /// Internal method for heapifying
-    /// the currently stored Vec<i32>.
+    /// the currently stored Vec<T>.
     fn heapify(&mut self) {
-        let mut ptr = (self.heap.len() - 1) / 2;
+        if self.heap.len() < 2 {
+            return;
+        }
 
-        while ptr >= 1 {
+        for ptr in (0..=(self.heap.len() - 2) / 2).rev() {
             self.sink(ptr);
-            ptr -= 1;
         }
     }
 
-    /// Retrieves and removes the k largest
-    /// elements of the MaxPQ.
+    /// Retrieves and removes the k largest elements of the
+    /// PriorityQueue. Shorter than k if the PriorityQueue runs out of
+    /// elements first.
     ///
     /// # Examples
     ///
     /// ```
     /// use rds::priorityqueue::*;
     ///
-    /// let mut mpq = MaxPQ::from(vec![1, 2, 3, 4, 5]);
-    /// assert_eq!(vec![5, 4, 3], mpq.top_k(3));
+    /// let mut pq = PriorityQueue::from(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(vec![5, 4, 3], pq.top_k(3));
     /// ```
-    pub fn top_k(&mut self, k: usize) -> Vec<i32> {
-        let mut values = vec![0; k];
+    pub fn top_k(&mut self, k: usize) -> Vec<T> {
+        let mut values = Vec::with_capacity(k);
 
-        for i in 0..k {
-            values[i] = self.del_max();
+        for _ in 0..k {
+            match self.del_max() {
+                Some(value) => values.push(value),
+                None => break,
+            }
         }
 
         values
@@ -145,17 +179,18 @@ impl MaxPQ {
     /// use rds::priorityqueue::*;
     ///
     /// let values = vec![5, 3, 4, 1, 2];
-    /// let sorted_values = MaxPQ::from(values).heapsort();
+    /// let sorted_values = PriorityQueue::from(values).heapsort();
     ///
     /// assert_eq!(sorted_values, vec![1, 2, 3, 4, 5]);
     /// ```
-    pub fn heapsort(&mut self) -> Vec<i32> {
-        let mut values = vec![0; self.heap.len() - 1];
+    pub fn heapsort(&mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.heap.len());
 
-        for i in (0..self.heap.len() - 1).rev() {
-            values[i] = self.del_max();
+        while let Some(value) = self.del_max() {
+            values.push(value);
         }
 
+        values.reverse();
         values
     }
 }
@@ -163,18 +198,45 @@ impl MaxPQ {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cmp::Reverse;
 
     #[test]
     fn heapsort() {
-        let mut mpq = MaxPQ::from(vec![5, 4, 3, 2, 1]);
+        let mut pq = PriorityQueue::from(vec![5, 4, 3, 2, 1]);
 
-        assert_eq!(vec![1, 2, 3, 4, 5], mpq.heapsort());
+        assert_eq!(vec![1, 2, 3, 4, 5], pq.heapsort());
     }
 
     #[test]
     fn top_5() {
-        let mut mpq = MaxPQ::from(vec![5, 4, 3, 2, 1]);
+        let mut pq = PriorityQueue::from(vec![5, 4, 3, 2, 1]);
+
+        assert_eq!(vec![5, 4, 3, 2, 1], pq.top_k(5));
+    }
+
+    #[test]
+    fn top_k_beyond_size_returns_fewer() {
+        let mut pq = PriorityQueue::from(vec![1, 2, 3]);
+
+        assert_eq!(vec![3, 2, 1], pq.top_k(10));
+    }
+
+    #[test]
+    fn min_queue_via_reverse() {
+        let mut pq = PriorityQueue::from(vec![5, 3, 4, 1, 2].into_iter().map(Reverse).collect());
+
+        assert_eq!(pq.del_max(), Some(Reverse(1)));
+        assert_eq!(pq.del_max(), Some(Reverse(2)));
+    }
+
+    #[test]
+    fn del_max_on_empty_queue_returns_none() {
+        let mut pq: PriorityQueue<i32> = PriorityQueue::empty();
+
+        assert_eq!(pq.del_max(), None);
 
-        assert_eq!(vec![5, 4, 3, 2, 1], mpq.top_k(5));
+        pq.insert(1);
+        assert_eq!(pq.del_max(), Some(1));
+        assert_eq!(pq.del_max(), None);
     }
 }